@@ -1,25 +1,76 @@
-use numbers::complex::Complex;
+use numbers::complex::{Complex, Float};
 use std::f64::consts::PI;
 
 pub mod numbers;
 
+/// Scaling convention applied to a forward/inverse transform pair.
+///
+/// Whatever the mode, a [`transform`](FFT::transform) followed by an
+/// [`inverse`](FFT::inverse) reproduces the original signal exactly; the
+/// variants only decide how the `1/n` factor is split between the two halves.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Normalization {
+    /// The forward transform is unscaled; the inverse divides by `n`.
+    #[default]
+    None,
+    /// Both directions scale by `1/sqrt(n)`, making the transform unitary.
+    Unitary,
+    /// The forward transform divides by `n`; the inverse is unscaled.
+    Inverse,
+}
+
+impl Normalization {
+    /// Divisor applied after the forward butterfly pass.
+    #[expect(clippy::cast_precision_loss)]
+    fn forward_divisor(self, n: usize) -> f64 {
+        match self {
+            Self::None => 1.0,
+            Self::Unitary => (n as f64).sqrt(),
+            Self::Inverse => n as f64,
+        }
+    }
+
+    /// Divisor applied after the inverse butterfly pass.
+    #[expect(clippy::cast_precision_loss)]
+    fn inverse_divisor(self, n: usize) -> f64 {
+        match self {
+            Self::None => n as f64,
+            Self::Unitary => (n as f64).sqrt(),
+            Self::Inverse => 1.0,
+        }
+    }
+}
+
 /// A Fast Fourier Transform (FFT) implementation.
+///
+/// Generic over the float precision `T`; use [`Complex32`](numbers::complex::Complex32)
+/// inputs for single precision and [`Complex64`](numbers::complex::Complex64) for double.
 #[derive(Debug, Default, Clone)]
-pub struct FFT {
-    twiddle_cache: Vec<Complex>,
+pub struct FFT<T = f64> {
+    twiddle_cache: Vec<Complex<T>>,
     current_size: usize,
+    normalization: Normalization,
 }
 
-impl FFT {
+impl<T: Float> FFT<T> {
     /// Creates a new FFT instance.
     #[must_use]
     pub const fn new() -> Self {
         Self {
             twiddle_cache: Vec::new(),
             current_size: 0,
+            normalization: Normalization::None,
         }
     }
 
+    /// Sets the scaling convention used by [`transform`](Self::transform) and
+    /// [`inverse`](Self::inverse).
+    #[must_use]
+    pub const fn with_normalization(mut self, normalization: Normalization) -> Self {
+        self.normalization = normalization;
+        self
+    }
+
     /// Computes the FFT of a complex signal.
     ///
     /// The input will be padded to the next power of 2 if necessary.
@@ -38,27 +89,222 @@ impl FFT {
     /// assert!((spectrum[0] - Complex::new(1.0, 0.0)).norm() < 1e-10);
     /// ```
     #[must_use]
-    pub fn transform(&mut self, signal: &[Complex]) -> Vec<Complex> {
-        let n = signal.len().next_power_of_two();
-        let mut padded = signal.to_vec();
-        padded.resize(n, Complex::new(0.0, 0.0));
+    pub fn transform(&mut self, signal: &[Complex<T>]) -> Vec<Complex<T>> {
+        let n = signal.len();
+        if n == 0 {
+            return Vec::new();
+        }
+
+        // Non-power-of-two inputs get the true length-`n` DFT via Bluestein
+        // instead of being silently zero-padded, which would corrupt the bins.
+        let mut spectrum = if n.is_power_of_two() {
+            let mut padded = signal.to_vec();
+            self.fft_inplace(&mut padded);
+            padded
+        } else {
+            self.bluestein(signal)
+        };
+
+        Self::scale(&mut spectrum, T::from_f64(self.normalization.forward_divisor(n)));
+        spectrum
+    }
+
+    /// Computes the exact length-`n` DFT for an arbitrary `n` using
+    /// Bluestein's chirp-z algorithm, which re-expresses the transform as a
+    /// convolution evaluated with the power-of-two radix-2 path.
+    fn bluestein(&mut self, signal: &[Complex<T>]) -> Vec<Complex<T>> {
+        let n = signal.len();
+        let m = (2 * n - 1).next_power_of_two();
+
+        // Chirp factors `w^(j^2/2) = exp(-i*pi*j^2/n)`; the squared index is
+        // reduced modulo `2n` before conversion so precision holds for large n.
+        let chirp: Vec<Complex<T>> = (0..n).map(|j| Self::chirp(j, n)).collect();
+
+        // `a[j] = x[j] * chirp[j]`, zero-padded to the convolution length.
+        let mut a = vec![Complex::new(T::ZERO, T::ZERO); m];
+        for (slot, (&s, &c)) in a.iter_mut().zip(signal.iter().zip(&chirp)) {
+            *slot = s * c;
+        }
+
+        // `b` is the conjugate chirp made symmetric so the circular
+        // convolution of length `m` agrees with the linear one on `0..n`.
+        let mut b = vec![Complex::new(T::ZERO, T::ZERO); m];
+        for (j, &c) in chirp.iter().enumerate() {
+            let conj = c.conj();
+            b[j] = conj;
+            if j != 0 {
+                b[m - j] = conj;
+            }
+        }
+
+        // Circular convolution: FFT both, multiply pointwise, inverse FFT.
+        self.fft_inplace(&mut a);
+        self.fft_inplace(&mut b);
+        for (x, &y) in a.iter_mut().zip(&b) {
+            *x *= y;
+        }
+        self.ifft_inplace(&mut a);
+
+        // `X[k] = chirp[k] * (a ⊛ b)[k]`.
+        chirp.iter().zip(&a).map(|(&c, &v)| c * v).collect()
+    }
 
-        self.fft_inplace(&mut padded);
-        padded
+    /// The Bluestein chirp factor `exp(-i*pi*j^2/n)`.
+    #[expect(clippy::cast_precision_loss)]
+    fn chirp(j: usize, n: usize) -> Complex<T> {
+        let modulus = 2 * n as u128;
+        let j = j as u128;
+        let j2 = (j * j % modulus) as f64;
+
+        Complex::from_polar(T::ONE, T::from_f64(-PI * j2 / n as f64))
+    }
+
+    /// In-place inverse FFT used internally by [`Self::bluestein`]; always
+    /// divides by the length so it is the exact reciprocal of `fft_inplace`.
+    #[expect(clippy::cast_precision_loss)]
+    fn ifft_inplace(&mut self, data: &mut [Complex<T>]) {
+        let len = data.len();
+
+        for x in data.iter_mut() {
+            *x = x.conj();
+        }
+        self.fft_inplace(data);
+        for x in data.iter_mut() {
+            *x = x.conj();
+        }
+
+        Self::scale(data, T::from_f64(len as f64));
     }
 
-    /// Computes the FFT of a real signal.
+    /// Computes the FFT of a real signal, exploiting Hermitian symmetry.
+    ///
+    /// Rather than widening every sample to a zero-imaginary [`Complex`] and
+    /// running a full `n`-point transform, the `n` real samples are packed into
+    /// an `n/2`-point complex array (even indices as real parts, odd indices as
+    /// imaginary parts) and a single `n/2`-point FFT is untangled back into the
+    /// spectrum — roughly halving the work.
+    ///
+    /// Only the non-redundant bins `0..=n/2` are returned; the remaining bins
+    /// are the complex conjugate mirror of these. The returned length is
+    /// therefore `n/2 + 1`, where `n` is the input length rounded up to the
+    /// next power of two.
     #[must_use]
-    pub fn transform_real(&mut self, signal: &[f64]) -> Vec<Complex> {
+    #[expect(clippy::cast_precision_loss)]
+    pub fn transform_real(&mut self, signal: &[T]) -> Vec<Complex<T>> {
         let n = signal.len().next_power_of_two();
-        let mut data = Vec::with_capacity(n);
-        data.extend(signal.iter().map(|&x| Complex::new(x, 0.0)));
-        data.resize(n, Complex::new(0.0, 0.0));
+        let half = n / 2;
+
+        // Too small to pack into an `n/2` array; widen and transform directly.
+        if half < 2 {
+            let mut data: Vec<Complex<T>> =
+                signal.iter().map(|&x| Complex::new(x, T::ZERO)).collect();
+            data.resize(n, Complex::new(T::ZERO, T::ZERO));
+
+            self.fft_inplace(&mut data);
+            Self::scale(&mut data, T::from_f64(self.normalization.forward_divisor(n)));
+            data.truncate(half + 1);
+
+            return data;
+        }
+
+        // Pack even-indexed samples into real parts and odd into imaginary.
+        let mut packed = vec![Complex::new(T::ZERO, T::ZERO); half];
+        for (i, slot) in packed.iter_mut().enumerate() {
+            let re = signal.get(2 * i).copied().unwrap_or(T::ZERO);
+            let im = signal.get(2 * i + 1).copied().unwrap_or(T::ZERO);
+            *slot = Complex::new(re, im);
+        }
+        self.fft_inplace(&mut packed);
+
+        // Untangle the `n/2`-point spectrum into the real `n`-point spectrum.
+        let two = T::from_f64(2.0);
+        let minus_i = Complex::new(T::ZERO, -T::ONE);
+        let mut spectrum = vec![Complex::new(T::ZERO, T::ZERO); half + 1];
+
+        // The DC and Nyquist bins are purely real combinations of `Z[0]`.
+        spectrum[0] = Complex::new(packed[0].re() + packed[0].im(), T::ZERO);
+        spectrum[half] = Complex::new(packed[0].re() - packed[0].im(), T::ZERO);
+
+        for k in 1..half {
+            let zk = packed[k];
+            let zc = packed[half - k].conj();
+
+            // Even/odd sub-spectra recovered from the packed transform.
+            let even = (zk + zc) / two;
+            let odd = (zk - zc) / two * minus_i;
+            let twiddle = Complex::from_polar(T::ONE, T::from_f64(-2.0 * PI * k as f64 / n as f64));
+
+            spectrum[k] = even + twiddle * odd;
+        }
+
+        Self::scale(&mut spectrum, T::from_f64(self.normalization.forward_divisor(n)));
+        spectrum
+    }
+
+    /// Computes the inverse FFT, mapping a spectrum back to the time domain.
+    ///
+    /// This reuses the forward butterfly machinery via the conjugation trick:
+    /// conjugating the input, running the forward pass and conjugating the
+    /// result yields the unnormalized inverse, which the active
+    /// [`Normalization`] then scales so that `inverse(transform(x)) == x`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fft_rs::FFT;
+    /// use fft_rs::numbers::complex::Complex;
+    ///
+    /// let mut fft = FFT::new();
+    /// let signal = vec![
+    ///     Complex::new(1.0, 0.0),
+    ///     Complex::new(2.0, 0.0),
+    ///     Complex::new(3.0, 0.0),
+    ///     Complex::new(4.0, 0.0),
+    /// ];
+    /// let spectrum = fft.transform(&signal);
+    /// let restored = fft.inverse(&spectrum);
+    ///
+    /// for (a, b) in signal.iter().zip(&restored) {
+    ///     assert!((*a - *b).norm() < 1e-10);
+    /// }
+    /// ```
+    #[must_use]
+    pub fn inverse(&mut self, spectrum: &[Complex<T>]) -> Vec<Complex<T>> {
+        let n = spectrum.len();
+        if n == 0 {
+            return Vec::new();
+        }
+
+        // Conjugate, run the forward path (Bluestein for non-power-of-two so we
+        // invert at the true length `n` rather than a padded one), conjugate back.
+        let conjugated: Vec<Complex<T>> = spectrum.iter().map(|c| c.conj()).collect();
+        let mut data = if n.is_power_of_two() {
+            let mut padded = conjugated;
+            self.fft_inplace(&mut padded);
+            padded
+        } else {
+            self.bluestein(&conjugated)
+        };
+
+        for x in &mut data {
+            *x = x.conj();
+        }
+        Self::scale(&mut data, T::from_f64(self.normalization.inverse_divisor(n)));
 
-        self.fft_inplace(&mut data);
         data
     }
 
+    /// Divides every sample by `divisor`, skipping the no-op `1.0` case.
+    fn scale(data: &mut [Complex<T>], divisor: T) {
+        if divisor == T::ONE {
+            return;
+        }
+
+        for x in data {
+            *x /= divisor;
+        }
+    }
+
     /// Pre-computes twiddle factors for a given size.
     #[expect(clippy::cast_precision_loss)]
     pub fn compute_twiddle_factors(&mut self, size: usize) {
@@ -70,11 +316,11 @@ impl FFT {
         self.twiddle_cache.reserve(size);
 
         // Compute base angle once.
-        let base_angle = -2.0 * PI / (size as f64);
+        let base_angle = T::from_f64(-2.0 * PI / (size as f64));
 
         // Generate factors using multiplication instead of repeated cos/sin.
-        let mut factor = Complex::new(1.0, 0.0);
-        let step = Complex::from_polar(1.0, base_angle);
+        let mut factor = Complex::new(T::ONE, T::ZERO);
+        let step = Complex::from_polar(T::ONE, base_angle);
 
         for _ in 0..size {
             self.twiddle_cache.push(factor);
@@ -84,7 +330,7 @@ impl FFT {
         self.current_size = size;
     }
 
-    fn fft_inplace(&mut self, data: &mut [Complex]) {
+    fn fft_inplace(&mut self, data: &mut [Complex<T>]) {
         let n = data.len();
         debug_assert!(n.is_power_of_two());
 
@@ -123,7 +369,7 @@ impl FFT {
         clippy::cast_sign_loss,
         clippy::cast_precision_loss
     )]
-    fn bit_reverse_permutation(data: &mut [Complex]) {
+    fn bit_reverse_permutation(data: &mut [Complex<T>]) {
         let n = data.len();
         let bits = (n as f64).log2() as u32;
 
@@ -170,10 +416,86 @@ mod tests {
     fn test_non_power_of_two() {
         let result = setup(&[1.0, 1.0, 1.0]);
 
-        // Result should be padded to length 4.
-        assert_eq!(result.len(), 4);
+        // Padded to length 4, but only the non-redundant n/2+1 bins are returned.
+        assert_eq!(result.len(), 3);
 
         // DC component should be sum of all samples.
         assert!((result[0].re() - 3.0).abs() < 1e-10);
     }
+
+    #[test]
+    fn test_real_fft_matches_complex() {
+        let samples = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0];
+
+        let real = setup(&samples);
+
+        let complex: Vec<Complex> = samples.iter().map(|&x| Complex::new(x, 0.0)).collect();
+        let mut fft = FFT::new();
+        let full = fft.transform(&complex);
+
+        // The real transform returns the non-redundant half of the spectrum.
+        assert_eq!(real.len(), samples.len() / 2 + 1);
+
+        for (bin, expected) in real.iter().zip(&full) {
+            assert!((*bin - *expected).norm() < 1e-10);
+        }
+    }
+
+    #[test]
+    #[expect(clippy::cast_precision_loss)]
+    fn test_bluestein_matches_naive_dft() {
+        let signal: Vec<Complex> = [3.0, 1.0, 4.0, 1.0, 5.0, 9.0, 2.0]
+            .iter()
+            .map(|&x| Complex::new(x, 0.0))
+            .collect();
+        let n = signal.len();
+
+        let mut fft = FFT::new();
+        let spectrum = fft.transform(&signal);
+
+        // The Bluestein path returns the true length-`n` spectrum.
+        assert_eq!(spectrum.len(), n);
+
+        for (k, &bin) in spectrum.iter().enumerate() {
+            let mut acc = Complex::new(0.0, 0.0);
+            for (j, &x) in signal.iter().enumerate() {
+                let angle = -2.0 * PI * (k * j) as f64 / n as f64;
+                acc += x * Complex::from_polar(1.0, angle);
+            }
+
+            assert!((bin - acc).norm() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_inverse_round_trip() {
+        let signal: Vec<Complex> = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0]
+            .iter()
+            .map(|&x| Complex::new(x, 0.0))
+            .collect();
+
+        let mut fft = FFT::new();
+        let spectrum = fft.transform(&signal);
+        let restored = fft.inverse(&spectrum);
+
+        for (a, b) in signal.iter().zip(&restored) {
+            assert!((*a - *b).norm() < 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_unitary_round_trip() {
+        let signal: Vec<Complex> = [1.0, -2.0, 3.0, -4.0]
+            .iter()
+            .map(|&x| Complex::new(x, 0.0))
+            .collect();
+
+        let mut fft = FFT::new().with_normalization(Normalization::Unitary);
+        let spectrum = fft.transform(&signal);
+        let restored = fft.inverse(&spectrum);
+
+        for (a, b) in signal.iter().zip(&restored) {
+            assert!((*a - *b).norm() < 1e-10);
+        }
+    }
 }
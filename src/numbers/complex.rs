@@ -1,5 +1,5 @@
 /*
-* A complex number can be visually represented as a pair of numbers (a, b)
+* A complex number can be visually represented as a pair of numbers (a, b)
 * forming a vector on a diagram called an Argand diagram, representing the
 * complex plane. Re is the real axis, Im is the imaginary axis, and i is the
 * "imaginary unit", that satisfies i2 = −1.
@@ -60,33 +60,178 @@
 * numbers do.
 */
 
-use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Rem, RemAssign, Sub, SubAssign};
+use num_traits::{Inv, MulAdd, One, Zero};
+use std::fmt;
+use std::iter::{Product, Sum};
+use std::ops::{
+    Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign,
+};
+use std::str::FromStr;
+
+/// The floating-point operations a [`Complex`] component needs.
+///
+/// Implemented for the primitive `f32` and `f64` types, this lets a single
+/// [`Complex<T>`] definition serve both single- and double-precision callers
+/// without depending on an external `Float` trait.
+pub trait Float:
+    Copy
+    + PartialOrd
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+    + Neg<Output = Self>
+{
+    /// The additive identity.
+    const ZERO: Self;
+    /// The multiplicative identity.
+    const ONE: Self;
+
+    /// Converts an `f64` literal into this float type.
+    fn from_f64(value: f64) -> Self;
+
+    #[must_use]
+    fn sin(self) -> Self;
+    #[must_use]
+    fn cos(self) -> Self;
+    #[must_use]
+    fn sinh(self) -> Self;
+    #[must_use]
+    fn cosh(self) -> Self;
+    #[must_use]
+    fn hypot(self, other: Self) -> Self;
+    #[must_use]
+    fn atan2(self, other: Self) -> Self;
+    #[must_use]
+    fn sqrt(self) -> Self;
+    #[must_use]
+    fn ln(self) -> Self;
+    #[must_use]
+    fn exp(self) -> Self;
+    #[must_use]
+    fn abs(self) -> Self;
+    #[must_use]
+    fn mul_add(self, a: Self, b: Self) -> Self;
+}
+
+impl Float for f64 {
+    const ZERO: Self = 0.0;
+    const ONE: Self = 1.0;
+
+    fn from_f64(value: f64) -> Self {
+        value
+    }
+
+    fn sin(self) -> Self {
+        Self::sin(self)
+    }
+    fn cos(self) -> Self {
+        Self::cos(self)
+    }
+    fn sinh(self) -> Self {
+        Self::sinh(self)
+    }
+    fn cosh(self) -> Self {
+        Self::cosh(self)
+    }
+    fn hypot(self, other: Self) -> Self {
+        Self::hypot(self, other)
+    }
+    fn atan2(self, other: Self) -> Self {
+        Self::atan2(self, other)
+    }
+    fn sqrt(self) -> Self {
+        Self::sqrt(self)
+    }
+    fn ln(self) -> Self {
+        Self::ln(self)
+    }
+    fn exp(self) -> Self {
+        Self::exp(self)
+    }
+    fn abs(self) -> Self {
+        Self::abs(self)
+    }
+    fn mul_add(self, a: Self, b: Self) -> Self {
+        Self::mul_add(self, a, b)
+    }
+}
+
+impl Float for f32 {
+    const ZERO: Self = 0.0;
+    const ONE: Self = 1.0;
+
+    #[expect(clippy::cast_possible_truncation)]
+    fn from_f64(value: f64) -> Self {
+        value as Self
+    }
+
+    fn sin(self) -> Self {
+        Self::sin(self)
+    }
+    fn cos(self) -> Self {
+        Self::cos(self)
+    }
+    fn sinh(self) -> Self {
+        Self::sinh(self)
+    }
+    fn cosh(self) -> Self {
+        Self::cosh(self)
+    }
+    fn hypot(self, other: Self) -> Self {
+        Self::hypot(self, other)
+    }
+    fn atan2(self, other: Self) -> Self {
+        Self::atan2(self, other)
+    }
+    fn sqrt(self) -> Self {
+        Self::sqrt(self)
+    }
+    fn ln(self) -> Self {
+        Self::ln(self)
+    }
+    fn exp(self) -> Self {
+        Self::exp(self)
+    }
+    fn abs(self) -> Self {
+        Self::abs(self)
+    }
+    fn mul_add(self, a: Self, b: Self) -> Self {
+        Self::mul_add(self, a, b)
+    }
+}
 
 #[derive(Debug, PartialEq, Clone, Copy)]
-pub struct Complex {
-    re: f64,
-    im: f64,
+pub struct Complex<T = f64> {
+    re: T,
+    im: T,
 }
 
-impl Complex {
+/// A single-precision complex number.
+pub type Complex32 = Complex<f32>;
+/// A double-precision complex number.
+pub type Complex64 = Complex<f64>;
+
+impl<T: Float> Complex<T> {
     #[must_use]
-    pub const fn new(re: f64, im: f64) -> Self {
+    pub const fn new(re: T, im: T) -> Self {
         Self { re, im }
     }
 
     #[must_use]
     #[inline]
-    pub const fn i() -> Self {
-        Self::new(0.0, 1.0)
+    pub fn i() -> Self {
+        Self::new(T::ZERO, T::ONE)
     }
 
     #[must_use]
-    pub const fn re(&self) -> f64 {
+    pub const fn re(&self) -> T {
         self.re
     }
 
     #[must_use]
-    pub const fn im(&self) -> f64 {
+    pub const fn im(&self) -> T {
         self.im
     }
 
@@ -107,17 +252,70 @@ impl Complex {
     }
 
     #[must_use]
-    pub fn from_polar(r: f64, theta: f64) -> Self {
+    pub fn from_polar(r: T, theta: T) -> Self {
         Self::new(r * theta.cos(), r * theta.sin())
     }
 
     #[must_use]
-    pub fn norm(self) -> f64 {
+    pub fn norm(self) -> T {
         self.re.hypot(self.im)
     }
+
+    /// The complex conjugate `a - bi`.
+    #[must_use]
+    pub fn conj(self) -> Self {
+        Self::new(self.re, -self.im)
+    }
+
+    /// The argument (phase angle) of the number, in radians.
+    #[must_use]
+    pub fn arg(self) -> T {
+        self.im.atan2(self.re)
+    }
+
+    /// The polar coordinates `(r, θ)`, with `r = norm` and `θ = arg`.
+    #[must_use]
+    pub fn to_polar(self) -> (T, T) {
+        (self.norm(), self.arg())
+    }
+
+    /// The complex exponential `e^z = e^a · (cos b + i·sin b)`.
+    #[must_use]
+    pub fn exp(self) -> Self {
+        Self::from_polar(self.re.exp(), self.im)
+    }
+
+    /// The principal natural logarithm `ln|z| + i·arg(z)`.
+    #[must_use]
+    pub fn ln(self) -> Self {
+        Self::new(self.norm().ln(), self.arg())
+    }
+
+    /// The principal square root, taking the imaginary part's sign from `im`.
+    #[must_use]
+    pub fn sqrt(self) -> Self {
+        let two = T::from_f64(2.0);
+        let r = self.norm();
+        let re = ((r + self.re) / two).sqrt();
+        let im = ((r - self.re) / two).sqrt();
+
+        Self::new(re, if self.im < T::ZERO { -im } else { im })
+    }
+
+    /// Raises `self` to a complex power via `exp(exp · ln(self))`.
+    #[must_use]
+    pub fn powc(self, exp: Self) -> Self {
+        (exp * self.ln()).exp()
+    }
+
+    /// Raises `self` to a real power.
+    #[must_use]
+    pub fn powf(self, exp: T) -> Self {
+        self.powc(Self::new(exp, T::ZERO))
+    }
 }
 
-impl Add for Complex {
+impl<T: Float> Add for Complex<T> {
     type Output = Self;
 
     /// a + b = (x + yi) + (u + vi) = (x + u) + (y + v)i
@@ -126,7 +324,7 @@ impl Add for Complex {
     }
 }
 
-impl AddAssign for Complex {
+impl<T: Float> AddAssign for Complex<T> {
     fn add_assign(&mut self, rhs: Self) {
         *self = Self {
             re: self.re + rhs.re,
@@ -135,7 +333,7 @@ impl AddAssign for Complex {
     }
 }
 
-impl Sub for Complex {
+impl<T: Float> Sub for Complex<T> {
     type Output = Self;
 
     /// a - b = (x + yi) - (u + vi) = (x - u) + (y - v)i
@@ -144,7 +342,7 @@ impl Sub for Complex {
     }
 }
 
-impl SubAssign for Complex {
+impl<T: Float> SubAssign for Complex<T> {
     fn sub_assign(&mut self, rhs: Self) {
         *self = Self {
             re: self.re - rhs.re,
@@ -153,7 +351,7 @@ impl SubAssign for Complex {
     }
 }
 
-impl Mul for Complex {
+impl<T: Float> Mul for Complex<T> {
     type Output = Self;
 
     /// (a + bi) * (c + di) = ac − bd + (ad + bc)i
@@ -165,7 +363,7 @@ impl Mul for Complex {
     }
 }
 
-impl MulAssign for Complex {
+impl<T: Float> MulAssign for Complex<T> {
     fn mul_assign(&mut self, rhs: Self) {
         *self = Self {
             re: self.re.mul_add(rhs.re, -(self.im * rhs.im)),
@@ -174,16 +372,53 @@ impl MulAssign for Complex {
     }
 }
 
-impl Div<f64> for Complex {
+impl<T: Float> Div for Complex<T> {
+    type Output = Self;
+
+    /// Complex division via Smith's algorithm, which divides through by the
+    /// larger of the divisor's parts to avoid overflow in the `c² + d²` denominator.
+    fn div(self, rhs: Self) -> Self::Output {
+        let (re, im, c_re, c_im) = (self.re, self.im, rhs.re, rhs.im);
+
+        if c_re.abs() >= c_im.abs() {
+            let ratio = c_im / c_re;
+            let den = c_im.mul_add(ratio, c_re);
+
+            Self::Output::new(im.mul_add(ratio, re) / den, re.mul_add(-ratio, im) / den)
+        } else {
+            let ratio = c_re / c_im;
+            let den = c_re.mul_add(ratio, c_im);
+
+            Self::Output::new(re.mul_add(ratio, im) / den, im.mul_add(ratio, -re) / den)
+        }
+    }
+}
+
+impl<T: Float> DivAssign for Complex<T> {
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs;
+    }
+}
+
+impl<T: Float> Neg for Complex<T> {
+    type Output = Self;
+
+    /// -(a + bi) = -a - bi
+    fn neg(self) -> Self::Output {
+        Self::Output::new(-self.re, -self.im)
+    }
+}
+
+impl<T: Float> Div<T> for Complex<T> {
     type Output = Self;
 
-    fn div(self, rhs: f64) -> Self::Output {
+    fn div(self, rhs: T) -> Self::Output {
         Self::Output::new(self.re / rhs, self.im / rhs)
     }
 }
 
-impl DivAssign<f64> for Complex {
-    fn div_assign(&mut self, rhs: f64) {
+impl<T: Float> DivAssign<T> for Complex<T> {
+    fn div_assign(&mut self, rhs: T) {
         *self = Self {
             re: self.re / rhs,
             im: self.im / rhs,
@@ -191,16 +426,108 @@ impl DivAssign<f64> for Complex {
     }
 }
 
-impl Rem<f64> for Complex {
+impl<T: Float + fmt::Display> fmt::Display for Complex<T> {
+    /// Renders the number as `a+bi`, flipping the separator to `a-bi` when the
+    /// imaginary part is negative.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.im < T::ZERO {
+            write!(f, "{}-{}i", self.re, -self.im)
+        } else {
+            write!(f, "{}+{}i", self.re, self.im)
+        }
+    }
+}
+
+impl<T: Float + fmt::LowerExp> fmt::LowerExp for Complex<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.im < T::ZERO {
+            write!(f, "{:e}-{:e}i", self.re, -self.im)
+        } else {
+            write!(f, "{:e}+{:e}i", self.re, self.im)
+        }
+    }
+}
+
+/// The error returned when a string cannot be parsed into a [`Complex`].
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct ParseComplexError {
+    message: String,
+}
+
+impl ParseComplexError {
+    fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for ParseComplexError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid complex number: {}", self.message)
+    }
+}
+
+impl std::error::Error for ParseComplexError {}
+
+impl<T: Float + FromStr> FromStr for Complex<T> {
+    type Err = ParseComplexError;
+
+    /// Parses forms such as `"2+3i"`, `"-4i"`, `"5"`, and `"1.5e-3-2i"`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if s.is_empty() {
+            return Err(ParseComplexError::new("empty input"));
+        }
+
+        let Some(rest) = s.strip_suffix('i').or_else(|| s.strip_suffix('I')) else {
+            // No trailing `i`, so the whole string is a real number.
+            return Ok(Self::new(parse_real(s)?, T::ZERO));
+        };
+
+        match split_terms(rest) {
+            // `re` followed by a signed imaginary term, e.g. "1.5e-3-2".
+            Some(idx) => Ok(Self::new(parse_real(&rest[..idx])?, parse_imag(&rest[idx..])?)),
+            // Pure imaginary, e.g. "-4" (from "-4i") or "" (from "i").
+            None => Ok(Self::new(T::ZERO, parse_imag(rest)?)),
+        }
+    }
+}
+
+/// Finds the `+`/`-` separating the real and imaginary terms, skipping a
+/// leading sign and any sign that is part of an exponent (`1.5e-3`).
+fn split_terms(s: &str) -> Option<usize> {
+    let bytes = s.as_bytes();
+
+    (1..bytes.len()).rev().find(|&i| {
+        matches!(bytes[i], b'+' | b'-') && !matches!(bytes[i - 1], b'e' | b'E')
+    })
+}
+
+fn parse_real<T: FromStr>(s: &str) -> Result<T, ParseComplexError> {
+    s.parse()
+        .map_err(|_| ParseComplexError::new(format!("malformed number `{s}`")))
+}
+
+/// Parses the imaginary coefficient, treating a bare or signed `i` as `±1`.
+fn parse_imag<T: Float + FromStr>(s: &str) -> Result<T, ParseComplexError> {
+    match s {
+        "" | "+" => Ok(T::ONE),
+        "-" => Ok(-T::ONE),
+        other => parse_real(other),
+    }
+}
+
+impl<T: Float> Rem<T> for Complex<T> {
     type Output = Self;
 
-    fn rem(self, rhs: f64) -> Self::Output {
+    fn rem(self, rhs: T) -> Self::Output {
         Self::Output::new(self.re % rhs, self.im % rhs)
     }
 }
 
-impl RemAssign<f64> for Complex {
-    fn rem_assign(&mut self, rhs: f64) {
+impl<T: Float> RemAssign<T> for Complex<T> {
+    fn rem_assign(&mut self, rhs: T) {
         *self = Self {
             re: self.re % rhs,
             im: self.im % rhs,
@@ -208,9 +535,61 @@ impl RemAssign<f64> for Complex {
     }
 }
 
+impl<T: Float> Zero for Complex<T> {
+    fn zero() -> Self {
+        Self::new(T::ZERO, T::ZERO)
+    }
+
+    fn is_zero(&self) -> bool {
+        self.re == T::ZERO && self.im == T::ZERO
+    }
+}
+
+impl<T: Float> One for Complex<T> {
+    fn one() -> Self {
+        Self::new(T::ONE, T::ZERO)
+    }
+}
+
+impl<T: Float> Inv for Complex<T> {
+    type Output = Self;
+
+    /// The reciprocal `conj(z) / |z|²`.
+    fn inv(self) -> Self::Output {
+        let norm_sq = self.re.mul_add(self.re, self.im * self.im);
+
+        self.conj() / norm_sq
+    }
+}
+
+impl<T: Float> MulAdd for Complex<T> {
+    type Output = Self;
+
+    /// The fused `self * a + b`.
+    fn mul_add(self, a: Self, b: Self) -> Self::Output {
+        let re = self.re.mul_add(a.re, (-self.im).mul_add(a.im, b.re));
+        let im = self.re.mul_add(a.im, self.im.mul_add(a.re, b.im));
+
+        Self::Output::new(re, im)
+    }
+}
+
+impl<T: Float> Sum for Complex<T> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::new(T::ZERO, T::ZERO), |acc, x| acc + x)
+    }
+}
+
+impl<T: Float> Product for Complex<T> {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::new(T::ONE, T::ZERO), |acc, x| acc * x)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use num_traits::{Inv, MulAdd, One, Zero};
 
     const fn setup() -> (Complex, Complex) {
         let a = Complex::new(5.0, 3.0);
@@ -260,6 +639,103 @@ mod tests {
         assert_eq!(expected, actual);
     }
 
+    #[test]
+    fn complex_div_complex() {
+        let (a, b) = setup();
+
+        // (5 + 3i) / (2 + 7i) = (31 - 29i) / 53.
+        let expected = Complex::new(31.0 / 53.0, -29.0 / 53.0);
+        let actual = a / b;
+
+        assert!((actual - expected).norm() < 1e-12);
+    }
+
+    #[test]
+    fn complex_neg() {
+        let (a, _) = setup();
+
+        let expected = Complex::new(-5.0, -3.0);
+        let actual = -a;
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn complex_exp_ln_round_trip() {
+        let (a, _) = setup();
+
+        let actual = a.ln().exp();
+
+        assert!((actual - a).norm() < 1e-12);
+    }
+
+    #[test]
+    fn complex_sqrt() {
+        // √(-1) = i on the principal branch.
+        let actual = Complex::new(-1.0, 0.0).sqrt();
+
+        assert!((actual - Complex::i()).norm() < 1e-12);
+
+        // Squaring the root returns the input.
+        let (a, _) = setup();
+        let root = a.sqrt();
+
+        assert!((root * root - a).norm() < 1e-12);
+    }
+
+    #[test]
+    fn complex_f32_precision() {
+        let a = Complex32::new(1.0, 2.0);
+        let b = Complex32::new(3.0, 4.0);
+
+        let expected = Complex32::new(-5.0, 10.0);
+        let actual = a * b;
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn complex_display() {
+        assert_eq!(Complex::new(2.0, 3.0).to_string(), "2+3i");
+        assert_eq!(Complex::new(2.0, -3.0).to_string(), "2-3i");
+        assert_eq!(format!("{:e}", Complex::new(1.5e-3, -2.0)), "1.5e-3-2e0i");
+    }
+
+    #[test]
+    fn complex_from_str() {
+        assert_eq!("2+3i".parse(), Ok(Complex::new(2.0, 3.0)));
+        assert_eq!("-4i".parse(), Ok(Complex::new(0.0, -4.0)));
+        assert_eq!("5".parse(), Ok(Complex::new(5.0, 0.0)));
+        assert_eq!("1.5e-3-2i".parse(), Ok(Complex::new(1.5e-3, -2.0)));
+        assert_eq!("-i".parse(), Ok(Complex::new(0.0, -1.0)));
+        assert_eq!("i".parse(), Ok(Complex::new(0.0, 1.0)));
+
+        assert!("".parse::<Complex>().is_err());
+        assert!("nonsense".parse::<Complex>().is_err());
+    }
+
+    #[test]
+    fn complex_num_traits() {
+        assert_eq!(<Complex as Zero>::zero(), Complex::new(0.0, 0.0));
+        assert!(Complex::new(0.0, 0.0).is_zero());
+        assert_eq!(<Complex as One>::one(), Complex::new(1.0, 0.0));
+
+        let (a, b) = setup();
+
+        // Inv is the reciprocal, so `z * z.inv() == 1`.
+        assert!((a * a.inv() - Complex::new(1.0, 0.0)).norm() < 1e-12);
+
+        // MulAdd computes `self * a + b`.
+        assert!((a.mul_add(b, b) - (a * b + b)).norm() < 1e-12);
+
+        let items = [a, b, Complex::new(1.0, -1.0)];
+        let sum: Complex = items.iter().copied().sum();
+        assert_eq!(sum, a + b + Complex::new(1.0, -1.0));
+
+        let product: Complex = items.iter().copied().product();
+        assert_eq!(product, a * b * Complex::new(1.0, -1.0));
+    }
+
     #[test]
     fn complex_mod() {
         let (a, _) = setup();